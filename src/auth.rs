@@ -0,0 +1,159 @@
+// === Auth subsystem ===
+//
+// Minimal JWT-based authentication on top of the existing `users` table:
+// `POST /auth/register` hashes the password with Argon2 and stores a PHC
+// string, `POST /auth/login` verifies it and mints a signed JWT. The
+// `AccessClaims` extractor lets handlers require a valid bearer token.
+
+use argon2::{
+    Argon2,
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString, rand_core::OsRng},
+};
+use axum::{
+    Json,
+    extract::{FromRequestParts, State},
+    http::{header, request::Parts},
+};
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use serde::{Deserialize, Serialize};
+use sqlx::AnyPool;
+use std::time::{SystemTime, UNIX_EPOCH};
+use utoipa::ToSchema;
+
+use crate::{AppError, AppState, Config, User};
+
+const TOKEN_TTL_SECS: u64 = 60 * 60; // 1 hour
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct RegisterRequest {
+    name: String,
+    password: String,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct LoginRequest {
+    name: String,
+    password: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct LoginResponse {
+    token: String,
+}
+
+// JWT claims: `sub` is the user id, `exp` is the standard expiry timestamp.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct Claims {
+    pub sub: String,
+    pub exp: u64,
+}
+
+// Extractor that requires a valid `Authorization: Bearer <jwt>` header.
+pub(crate) struct AccessClaims(pub Claims);
+
+impl FromRequestParts<AppState> for AccessClaims {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let auth_header = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .ok_or(AppError::Unauthorized)?;
+
+        let token = auth_header
+            .strip_prefix("Bearer ")
+            .ok_or(AppError::Unauthorized)?;
+
+        let data = decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(state.config.jwt_secret.as_bytes()),
+            &Validation::default(),
+        )
+        .map_err(|_| AppError::Unauthorized)?;
+
+        Ok(AccessClaims(data.claims))
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/register",
+    tag = "Auth",
+    request_body = RegisterRequest,
+    responses((status = 201, body = User)),
+    description = "Register a new user with a hashed password"
+)]
+pub(crate) async fn register(
+    State(pool): State<AnyPool>,
+    Json(req): Json<RegisterRequest>,
+) -> Result<(axum::http::StatusCode, Json<User>), AppError> {
+    if req.name.trim().is_empty() || req.password.is_empty() {
+        return Err(AppError::Validation(
+            "Name and password must not be empty".into(),
+        ));
+    }
+
+    let salt = SaltString::generate(OsRng);
+    let password_hash = Argon2::default()
+        .hash_password(req.password.as_bytes(), &salt)
+        .map_err(|e| AppError::Validation(e.to_string()))?
+        .to_string();
+
+    let user = sqlx::query_as::<_, User>(
+        "INSERT INTO users (name, password_hash) VALUES (?, ?) RETURNING id, name, password_hash",
+    )
+    .bind(req.name)
+    .bind(password_hash)
+    .fetch_one(&pool)
+    .await?;
+
+    Ok((axum::http::StatusCode::CREATED, Json(user)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/login",
+    tag = "Auth",
+    request_body = LoginRequest,
+    responses((status = 200, body = LoginResponse), (status = 401)),
+    description = "Log in and receive a signed JWT"
+)]
+pub(crate) async fn login(
+    State(pool): State<AnyPool>,
+    State(config): State<Config>,
+    Json(req): Json<LoginRequest>,
+) -> Result<Json<LoginResponse>, AppError> {
+    let user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE name = ?")
+        .bind(req.name)
+        .fetch_optional(&pool)
+        .await?
+        .ok_or(AppError::Unauthorized)?;
+
+    let parsed_hash =
+        PasswordHash::new(&user.password_hash).map_err(|_| AppError::Unauthorized)?;
+    Argon2::default()
+        .verify_password(req.password.as_bytes(), &parsed_hash)
+        .map_err(|_| AppError::Unauthorized)?;
+
+    let expires_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| AppError::Validation(e.to_string()))?
+        .as_secs()
+        + TOKEN_TTL_SECS;
+    let claims = Claims {
+        sub: user.id.to_string(),
+        exp: expires_at,
+    };
+    let token = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(config.jwt_secret.as_bytes()),
+    )
+    .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    Ok(Json(LoginResponse { token }))
+}
@@ -0,0 +1,134 @@
+// === Runtime configuration ===
+//
+// Everything that used to be hardcoded (the database URL, bind address,
+// request timeout, CORS origins, and JWT secret) now comes from the
+// environment, with sensible defaults so `cargo run` still works out of
+// the box.
+
+use axum::http::HeaderValue;
+use std::{net::SocketAddr, time::Duration};
+
+use crate::AppError;
+
+const DEFAULT_DATABASE_URL: &str = "sqlite::memory:";
+const DEFAULT_BIND_ADDR: &str = "127.0.0.1:3000";
+const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 10;
+const DEFAULT_CORS_ALLOWED_ORIGINS: &str = "*";
+const DEFAULT_JWT_SECRET: &str = "dev-only-secret-do-not-use-in-production";
+
+// Either wide open (`*`, the default) or a fixed list of allowed origins.
+#[derive(Clone)]
+pub(crate) enum CorsOrigins {
+    Any,
+    List(Vec<HeaderValue>),
+}
+
+#[derive(Clone)]
+pub(crate) struct Config {
+    pub database_url: String,
+    pub bind_addr: SocketAddr,
+    pub request_timeout: Duration,
+    pub cors_allowed_origins: CorsOrigins,
+    pub jwt_secret: String,
+}
+
+impl Config {
+    pub fn from_env() -> Result<Self, AppError> {
+        let database_url =
+            std::env::var("DATABASE_URL").unwrap_or_else(|_| DEFAULT_DATABASE_URL.into());
+
+        let bind_addr = std::env::var("BIND_ADDR")
+            .unwrap_or_else(|_| DEFAULT_BIND_ADDR.into())
+            .parse::<SocketAddr>()
+            .map_err(|e| AppError::Startup(format!("BIND_ADDR: {e}")))?;
+
+        let request_timeout_secs = std::env::var("REQUEST_TIMEOUT_SECS")
+            .ok()
+            .map(|v| v.parse::<u64>())
+            .transpose()
+            .map_err(|e| AppError::Startup(format!("REQUEST_TIMEOUT_SECS: {e}")))?
+            .unwrap_or(DEFAULT_REQUEST_TIMEOUT_SECS);
+
+        let cors_allowed_origins = parse_cors_origins(
+            &std::env::var("CORS_ALLOWED_ORIGINS")
+                .unwrap_or_else(|_| DEFAULT_CORS_ALLOWED_ORIGINS.into()),
+        )?;
+
+        let jwt_secret = std::env::var("JWT_SECRET").unwrap_or_else(|_| DEFAULT_JWT_SECRET.into());
+
+        Ok(Config {
+            database_url,
+            bind_addr,
+            request_timeout: Duration::from_secs(request_timeout_secs),
+            cors_allowed_origins,
+            jwt_secret,
+        })
+    }
+}
+
+fn parse_cors_origins(raw: &str) -> Result<CorsOrigins, AppError> {
+    if raw.trim() == "*" {
+        return Ok(CorsOrigins::Any);
+    }
+
+    let origins = raw
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|origin| {
+            HeaderValue::from_str(origin)
+                .map_err(|e| AppError::Startup(format!("CORS_ALLOWED_ORIGINS: {e}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(CorsOrigins::List(origins))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_wildcard_as_any() {
+        assert!(matches!(
+            parse_cors_origins("*").unwrap(),
+            CorsOrigins::Any
+        ));
+    }
+
+    #[test]
+    fn parses_comma_separated_origin_list() {
+        let origins = parse_cors_origins("https://a.example, https://b.example").unwrap();
+        match origins {
+            CorsOrigins::List(list) => assert_eq!(list.len(), 2),
+            CorsOrigins::Any => panic!("expected an explicit list"),
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_origin() {
+        assert!(parse_cors_origins("not a valid\nheader value").is_err());
+    }
+
+    // These two tests may run on parallel threads, and `Config::from_env` reads
+    // every var, so each can see the other's malformed value; both values make
+    // `from_env` fail with `AppError::Startup`, so the assertions hold under any
+    // interleaving. Each var is removed again before returning.
+    #[test]
+    fn malformed_bind_addr_is_startup_error() {
+        unsafe { std::env::set_var("BIND_ADDR", "not-a-socket-addr") };
+        let result = Config::from_env();
+        unsafe { std::env::remove_var("BIND_ADDR") };
+
+        assert!(matches!(result, Err(AppError::Startup(_))));
+    }
+
+    #[test]
+    fn malformed_request_timeout_secs_is_startup_error() {
+        unsafe { std::env::set_var("REQUEST_TIMEOUT_SECS", "not-a-number") };
+        let result = Config::from_env();
+        unsafe { std::env::remove_var("REQUEST_TIMEOUT_SECS") };
+
+        assert!(matches!(result, Err(AppError::Startup(_))));
+    }
+}
@@ -1,31 +1,56 @@
 use axum::{
     Json, Router,
-    extract::{Path, State},
+    extract::{FromRef, Path, Query, State},
     http::StatusCode,
     response::{IntoResponse, Response},
-    routing::get,
+    routing::{get, post},
 }; // Web framework
 use serde::{Deserialize, Serialize}; // Serialization and deserialization
-use sqlx::{FromRow, SqlitePool, sqlite::SqlitePoolOptions}; // Database interaction
-use std::{net::SocketAddr, time::Duration};
+use sqlx::{
+    AnyPool, FromRow,
+    any::{AnyPoolOptions, install_default_drivers},
+}; // Database interaction
 use thiserror::Error; // Error handling
 use tokio::signal; // Async runtime
 use tower::ServiceBuilder; // HTTP server
-use tower_http::{
-    cors::{Any, CorsLayer},
-    timeout::TimeoutLayer,
-    trace::TraceLayer,
-}; // Middleware, CORS, and tracing
+use tower_http::{cors::CorsLayer, timeout::TimeoutLayer, trace::TraceLayer}; // Middleware, CORS, and tracing
 use utoipa::{OpenApi, ToSchema};
 use utoipa_swagger_ui::SwaggerUi; // Automatic OpenAPI documentation
 
+mod auth;
+mod config;
+
+use auth::AccessClaims;
+use config::{Config, CorsOrigins};
+
+// Shared application state handed to every handler via `State<AppState>`.
+#[derive(Clone)]
+pub(crate) struct AppState {
+    pub(crate) pool: AnyPool,
+    pub(crate) config: Config,
+}
+
+impl FromRef<AppState> for AnyPool {
+    fn from_ref(state: &AppState) -> Self {
+        state.pool.clone()
+    }
+}
+
+impl FromRef<AppState> for Config {
+    fn from_ref(state: &AppState) -> Self {
+        state.config.clone()
+    }
+}
+
 // === Domain models ===
 
 // User database model
 #[derive(Serialize, Deserialize, FromRow, ToSchema)]
-struct User {
+pub(crate) struct User {
     id: i32,
     name: String,
+    #[serde(skip_serializing, default)]
+    password_hash: String,
 }
 
 // User DTO (Data Transfer Object) model
@@ -38,15 +63,32 @@ struct NewUser {
 
 // Exhaustive enum of all possible errors
 #[derive(Debug, Error)]
-enum AppError {
+pub(crate) enum AppError {
     #[error("DB: {0}")]
-    Sqlx(#[from] sqlx::Error),
+    Sqlx(sqlx::Error),
     #[error("Not found")]
     NotFound,
     #[error("Validation: {0}")]
     Validation(String),
     #[error("Startup: {0}")]
     Startup(String),
+    #[error("Unauthorized")]
+    Unauthorized,
+    #[error("Conflict: {0}")]
+    Conflict(String),
+}
+
+// Map database errors by hand instead of a blanket `#[from]` so unique-constraint
+// violations can be surfaced as a friendly 409 instead of a generic 500.
+impl From<sqlx::Error> for AppError {
+    fn from(err: sqlx::Error) -> Self {
+        if let sqlx::Error::Database(db_err) = &err {
+            if db_err.is_unique_violation() {
+                return AppError::Conflict("User already exists".into());
+            }
+        }
+        AppError::Sqlx(err)
+    }
 }
 
 // Implement the IntoResponse trait for the AppError enum to convert it into a proper HTTP response
@@ -55,6 +97,8 @@ impl IntoResponse for AppError {
         let status = match self {
             AppError::NotFound => StatusCode::NOT_FOUND,
             AppError::Validation(_) => StatusCode::BAD_REQUEST,
+            AppError::Unauthorized => StatusCode::UNAUTHORIZED,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         };
         let body = serde_json::json!({ "error": self.to_string() });
@@ -64,18 +108,78 @@ impl IntoResponse for AppError {
 
 // === API handlers ===
 
+const DEFAULT_LIMIT: i64 = 20;
+const MAX_LIMIT: i64 = 100;
+
+// Escape `\`, `%`, and `_` so a `name_contains` value is matched literally
+// instead of as a `LIKE` pattern (paired with `ESCAPE '\'` in the query).
+fn escape_like_pattern(raw: &str) -> String {
+    raw.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+// Query string for `GET /users`: all fields are optional.
+#[derive(Deserialize)]
+struct ListParams {
+    limit: Option<i64>,
+    offset: Option<i64>,
+    name_contains: Option<String>,
+}
+
+// Paginated envelope returned by `GET /users`.
+#[derive(Serialize, Deserialize, ToSchema)]
+struct PaginatedUsers {
+    items: Vec<User>,
+    total: i64,
+    limit: i64,
+    offset: i64,
+}
+
 #[utoipa::path(
     get,
     path = "/users",
     tag = "User Service",
-    responses((status = 200, body = [User])),
-    description = "Get all users"
+    params(
+        ("limit" = Option<i64>, Query, description = "Max number of users to return (default 20, capped at 100)"),
+        ("offset" = Option<i64>, Query, description = "Number of users to skip (default 0)"),
+        ("name_contains" = Option<String>, Query, description = "Filter to users whose name contains this substring")
+    ),
+    responses((status = 200, body = PaginatedUsers)),
+    description = "Get all users, paginated and optionally filtered by name"
 )]
-async fn get_users(State(pool): State<SqlitePool>) -> Result<Json<Vec<User>>, AppError> {
-    let users = sqlx::query_as::<_, User>("SELECT * FROM users")
-        .fetch_all(&pool)
-        .await?;
-    Ok(Json(users))
+async fn get_users(
+    State(pool): State<AnyPool>,
+    Query(params): Query<ListParams>,
+) -> Result<Json<PaginatedUsers>, AppError> {
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let offset = params.offset.unwrap_or(0).max(0);
+    let name_pattern = params
+        .name_contains
+        .map(|name| format!("%{}%", escape_like_pattern(&name)))
+        .unwrap_or_else(|| "%".into());
+
+    let items = sqlx::query_as::<_, User>(
+        "SELECT * FROM users WHERE name LIKE ? ESCAPE '\\' LIMIT ? OFFSET ?",
+    )
+    .bind(&name_pattern)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(&pool)
+    .await?;
+
+    let total: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE name LIKE ? ESCAPE '\\'")
+            .bind(&name_pattern)
+            .fetch_one(&pool)
+            .await?;
+
+    Ok(Json(PaginatedUsers {
+        items,
+        total,
+        limit,
+        offset,
+    }))
 }
 
 #[utoipa::path(
@@ -88,7 +192,7 @@ async fn get_users(State(pool): State<SqlitePool>) -> Result<Json<Vec<User>>, Ap
 )]
 async fn get_user(
     Path(id): Path<i32>,
-    State(pool): State<SqlitePool>,
+    State(pool): State<AnyPool>,
 ) -> Result<Json<User>, AppError> {
     sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = ?")
         .bind(id)
@@ -103,11 +207,12 @@ async fn get_user(
     path = "/users",
     tag = "User Service",
     request_body = NewUser,
-    responses((status = 201, body = User)),
-    description = "Create a new user"
+    responses((status = 201, body = User), (status = 401)),
+    description = "Create a new user (requires a valid bearer token)"
 )]
 async fn create_user(
-    State(pool): State<SqlitePool>,
+    State(pool): State<AnyPool>,
+    _claims: AccessClaims,
     Json(new): Json<NewUser>,
 ) -> Result<(StatusCode, Json<User>), AppError> {
     if new.name.trim().is_empty() {
@@ -116,14 +221,71 @@ async fn create_user(
 
     // Be sure to immediately return the id of the new user
     // to make it easier to use in the frontend client or API client
-    let user = sqlx::query_as::<_, User>("INSERT INTO users (name) VALUES (?) RETURNING id, name")
-        .bind(new.name)
-        .fetch_one(&pool)
-        .await?;
+    let user = sqlx::query_as::<_, User>(
+        "INSERT INTO users (name, password_hash) VALUES (?, '') RETURNING id, name, password_hash",
+    )
+    .bind(new.name)
+    .fetch_one(&pool)
+    .await?;
 
     Ok((StatusCode::CREATED, Json(user)))
 }
 
+#[utoipa::path(
+    put,
+    path = "/users/{id}",
+    tag = "User Service",
+    params(("id" = i32, Path)),
+    request_body = NewUser,
+    responses((status = 200, body = User), (status = 404), (status = 401)),
+    description = "Update a user's name"
+)]
+async fn update_user(
+    Path(id): Path<i32>,
+    State(pool): State<AnyPool>,
+    _claims: AccessClaims,
+    Json(new): Json<NewUser>,
+) -> Result<Json<User>, AppError> {
+    if new.name.trim().is_empty() {
+        return Err(AppError::Validation("Name must not be empty".into()));
+    }
+
+    sqlx::query_as::<_, User>(
+        "UPDATE users SET name = ? WHERE id = ? RETURNING id, name, password_hash",
+    )
+    .bind(new.name)
+    .bind(id)
+    .fetch_optional(&pool)
+    .await?
+    .map(Json)
+    .ok_or(AppError::NotFound)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/users/{id}",
+    tag = "User Service",
+    params(("id" = i32, Path)),
+    responses((status = 204), (status = 404), (status = 401)),
+    description = "Delete a user"
+)]
+async fn delete_user(
+    Path(id): Path<i32>,
+    State(pool): State<AnyPool>,
+    _claims: AccessClaims,
+) -> Result<StatusCode, AppError> {
+    let result = sqlx::query("DELETE FROM users WHERE id = ?")
+        .bind(id)
+        .execute(&pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound);
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 // Health check route
 #[utoipa::path(
     get,
@@ -180,8 +342,24 @@ A lightweight REST API for managing users, built with:
 
 #[derive(OpenApi)]
 #[openapi(
-    paths(create_user, get_users, get_user, health),
-    components(schemas(User, NewUser)),
+    paths(
+        create_user,
+        get_users,
+        get_user,
+        update_user,
+        delete_user,
+        health,
+        auth::register,
+        auth::login
+    ),
+    components(schemas(
+        User,
+        NewUser,
+        PaginatedUsers,
+        auth::RegisterRequest,
+        auth::LoginRequest,
+        auth::LoginResponse
+    )),
     info(
         title = "User API",
         version = "0.1.0",
@@ -191,50 +369,73 @@ A lightweight REST API for managing users, built with:
 struct ApiDoc;
 
 // === Database Initialization ===
-async fn initialize_database() -> Result<SqlitePool, AppError> {
-    // Using an in-memory SQLite database for simplicity
-    // In a production app you would use a persistent database such as PostgreSQL, AWS RDS, GCP Cloud SQL, etc.
-    let pool = SqlitePoolOptions::new()
-        .connect("sqlite::memory:")
+// Works against either SQLite or Postgres, selected by the `DATABASE_URL` scheme,
+// via sqlx's `Any` driver. DDL isn't portable across the two (e.g. autoincrementing
+// integer primary keys), so each backend gets its own migration directory under
+// `migrations/`; both are the single source of truth shared by `main` and the test
+// harness instead of duplicated ad-hoc DDL.
+static SQLITE_MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations/sqlite");
+static POSTGRES_MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations/postgres");
+
+async fn initialize_database(database_url: &str) -> Result<AnyPool, AppError> {
+    install_default_drivers();
+
+    let pool = AnyPoolOptions::new()
+        .connect(database_url)
         .await
         .map_err(|e| AppError::Startup(e.to_string()))?;
 
-    // Create the schema
-    // In production, we would use migrations
-    for sql in [
-        "CREATE TABLE IF NOT EXISTS users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)",
-        "INSERT INTO users (name) VALUES ('Alice'), ('Bob')",
-    ] {
-        sqlx::query(sql).execute(&pool).await?;
-    }
+    let migrator = if database_url.starts_with("postgres") {
+        &POSTGRES_MIGRATOR
+    } else {
+        &SQLITE_MIGRATOR
+    };
+
+    migrator
+        .run(&pool)
+        .await
+        .map_err(|e| AppError::Startup(e.to_string()))?;
 
     Ok(pool)
 }
 
 // === Router Setup ===
-fn build_router(pool: SqlitePool) -> Router {
+fn build_router(pool: AnyPool, config: Config) -> Router {
+    let cors = match config.cors_allowed_origins.clone() {
+        CorsOrigins::Any => CorsLayer::new().allow_origin(tower_http::cors::Any),
+        CorsOrigins::List(origins) => CorsLayer::new().allow_origin(origins),
+    };
+    let timeout = config.request_timeout;
+    let state = AppState { pool, config };
+
     Router::new()
         .route("/users", get(get_users).post(create_user))
-        .route("/users/{id}", get(get_user))
+        .route(
+            "/users/{id}",
+            get(get_user).put(update_user).delete(delete_user),
+        )
+        .route("/auth/register", post(auth::register))
+        .route("/auth/login", post(auth::login))
         .route("/health", get(health))
         .fallback(fallback)
-        .with_state(pool)
+        .with_state(state)
         .merge(SwaggerUi::new("/docs").url("/api-doc/openapi.json", ApiDoc::openapi()))
         .layer(
             ServiceBuilder::new()
                 .layer(TraceLayer::new_for_http())
-                .layer(CorsLayer::new().allow_origin(Any))
-                .layer(TimeoutLayer::new(Duration::from_secs(10))),
+                .layer(cors)
+                .layer(TimeoutLayer::new(timeout)),
         )
 }
 
 // === Main Entrypoint ===
 #[tokio::main]
 async fn main() -> Result<(), AppError> {
-    let pool = initialize_database().await?;
-    let app = build_router(pool);
+    let config = Config::from_env()?;
+    let pool = initialize_database(&config.database_url).await?;
+    let addr = config.bind_addr;
+    let app = build_router(pool, config);
 
-    let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
     println!("Docs available at http://{}/docs", addr);
 
     let listener = tokio::net::TcpListener::bind(addr)
@@ -255,38 +456,54 @@ mod tests {
     use super::*;
     use axum::body::Body;
     use axum::http::{Request, StatusCode};
+    use std::time::Duration;
     use tower::ServiceExt; // for `oneshot`
 
+    fn test_config() -> Config {
+        Config {
+            database_url: "sqlite::memory:".into(),
+            bind_addr: "127.0.0.1:0".parse().unwrap(),
+            request_timeout: Duration::from_secs(10),
+            cors_allowed_origins: CorsOrigins::Any,
+            jwt_secret: "test-secret".into(),
+        }
+    }
+
     async fn setup_test_app() -> Router {
-        // Use the `initialize_database` function to set up an in-memory database for testing
-        let pool = SqlitePoolOptions::new()
-            .connect("sqlite::memory:")
-            .await
-            .unwrap();
+        // Run the same migrations as production against a fresh in-memory database,
+        // so tests and production share one schema source of truth.
+        let pool = initialize_database("sqlite::memory:").await.unwrap();
 
-        // Create the schema for testing
-        sqlx::query("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
-            .execute(&pool)
-            .await
-            .unwrap();
+        // Use the `build_router` function to create the app
+        build_router(pool, test_config())
+    }
+
+    // Exercises the Postgres path of `initialize_database`: runs the Postgres migrations
+    // against an actual server and checks the seeded rows. Ignored by default since CI/dev machines don't all
+    // have one running; set POSTGRES_TEST_DATABASE_URL and pass `--ignored` to run it.
+    #[tokio::test]
+    #[ignore = "requires a real Postgres instance; set POSTGRES_TEST_DATABASE_URL and run with `cargo test -- --ignored`"]
+    async fn test_postgres_migrations_run_successfully() {
+        let database_url = std::env::var("POSTGRES_TEST_DATABASE_URL")
+            .expect("POSTGRES_TEST_DATABASE_URL must be set to run this test");
+
+        let pool = initialize_database(&database_url).await.unwrap();
 
-        // Seed initial data
-        sqlx::query("INSERT INTO users (name) VALUES ('Alice'), ('Bob')")
-            .execute(&pool)
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+            .fetch_one(&pool)
             .await
             .unwrap();
-
-        // Use the `build_router` function to create the app
-        build_router(pool)
+        assert_eq!(count, 2);
     }
 
     #[tokio::test]
     async fn test_health_check() {
         // Arrange
-        let pool = SqlitePoolOptions::new()
+        install_default_drivers();
+        let pool = AnyPoolOptions::new()
             .connect_lazy("sqlite::memory:")
-            .expect("Failed to create SQLite pool");
-        let app = build_router(pool);
+            .expect("Failed to create database pool");
+        let app = build_router(pool, test_config());
 
         // Act
         let response = app
@@ -328,10 +545,98 @@ mod tests {
         let body = axum::body::to_bytes(response.into_body(), 1024 * 1024)
             .await
             .unwrap();
-        let users: Vec<User> = serde_json::from_slice(&body).unwrap();
-        assert_eq!(users.len(), 2);
-        assert_eq!(users[0].name, "Alice");
-        assert_eq!(users[1].name, "Bob");
+        let page: PaginatedUsers = serde_json::from_slice(&body).unwrap();
+        assert_eq!(page.total, 2);
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items[0].name, "Alice");
+        assert_eq!(page.items[1].name, "Bob");
+    }
+
+    #[tokio::test]
+    async fn test_get_users_pagination_and_name_filter() {
+        // Arrange
+        let app = setup_test_app().await;
+        let token = register_and_login(&app, "judy", "hunter2").await;
+        for name in ["Charlie", "Charlotte", "Dave"] {
+            let new_user = serde_json::json!({ "name": name });
+            app.clone()
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri("/users")
+                        .header("Content-Type", "application/json")
+                        .header("Authorization", format!("Bearer {token}"))
+                        .body(Body::from(new_user.to_string()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+        }
+
+        // Act: limit the page size and filter by a substring shared by two users
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/users?limit=1&offset=0&name_contains=Char")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), 1024 * 1024)
+            .await
+            .unwrap();
+        let page: PaginatedUsers = serde_json::from_slice(&body).unwrap();
+        assert_eq!(page.total, 2);
+        assert_eq!(page.limit, 1);
+        assert_eq!(page.offset, 0);
+        assert_eq!(page.items.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_users_name_filter_escapes_like_wildcards() {
+        // Arrange
+        let app = setup_test_app().await;
+        let token = register_and_login(&app, "kevin", "hunter2").await;
+        for name in ["bo_b", "bob"] {
+            let new_user = serde_json::json!({ "name": name });
+            app.clone()
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri("/users")
+                        .header("Content-Type", "application/json")
+                        .header("Authorization", format!("Bearer {token}"))
+                        .body(Body::from(new_user.to_string()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+        }
+
+        // Act: "_" should match only the literal underscore, not act as a
+        // single-character wildcard matching every seeded user too.
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/users?name_contains=_")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), 1024 * 1024)
+            .await
+            .unwrap();
+        let page: PaginatedUsers = serde_json::from_slice(&body).unwrap();
+        assert_eq!(page.total, 1);
+        assert_eq!(page.items[0].name, "bo_b");
     }
 
     #[tokio::test]
@@ -359,10 +664,69 @@ mod tests {
         assert_eq!(error_message["error"], "Not found");
     }
 
+    async fn register_and_login(app: &Router, name: &str, password: &str) -> String {
+        let register_body = serde_json::json!({ "name": name, "password": password });
+        app.clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/auth/register")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(register_body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let login_body = serde_json::json!({ "name": name, "password": password });
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/auth/login")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(login_body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), 1024 * 1024)
+            .await
+            .unwrap();
+        let login: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        login["token"].as_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_create_user_requires_auth() {
+        // Arrange
+        let app = setup_test_app().await;
+
+        // Act
+        let new_user = serde_json::json!({ "name": "Charlie" });
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/users")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(new_user.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
     #[tokio::test]
-    async fn test_create_user() {
+    async fn test_create_user_with_valid_token() {
         // Arrange
         let app = setup_test_app().await;
+        let token = register_and_login(&app, "dave", "hunter2").await;
 
         // Act
         let new_user = serde_json::json!({ "name": "Charlie" });
@@ -372,6 +736,7 @@ mod tests {
                     .method("POST")
                     .uri("/users")
                     .header("Content-Type", "application/json")
+                    .header("Authorization", format!("Bearer {token}"))
                     .body(Body::from(new_user.to_string()))
                     .unwrap(),
             )
@@ -386,4 +751,170 @@ mod tests {
         let created_user: User = serde_json::from_slice(&body).unwrap();
         assert_eq!(created_user.name, "Charlie");
     }
+
+    #[tokio::test]
+    async fn test_create_user_duplicate_name_is_conflict() {
+        // Arrange
+        let app = setup_test_app().await;
+        let token = register_and_login(&app, "dave", "hunter2").await;
+
+        // Act: "Alice" is already seeded
+        let new_user = serde_json::json!({ "name": "Alice" });
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/users")
+                    .header("Content-Type", "application/json")
+                    .header("Authorization", format!("Bearer {token}"))
+                    .body(Body::from(new_user.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let body = axum::body::to_bytes(response.into_body(), 1024 * 1024)
+            .await
+            .unwrap();
+        let error_message: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error_message["error"], "Conflict: User already exists");
+    }
+
+    #[tokio::test]
+    async fn test_login_with_wrong_password_is_unauthorized() {
+        // Arrange
+        let app = setup_test_app().await;
+        let register_body = serde_json::json!({ "name": "eve", "password": "correct-horse" });
+        app.clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/auth/register")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(register_body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // Act
+        let login_body = serde_json::json!({ "name": "eve", "password": "wrong" });
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/auth/login")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(login_body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_update_user() {
+        // Arrange
+        let app = setup_test_app().await;
+        let token = register_and_login(&app, "frank", "hunter2").await;
+
+        // Act
+        let update = serde_json::json!({ "name": "Alicia" });
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri("/users/1")
+                    .header("Content-Type", "application/json")
+                    .header("Authorization", format!("Bearer {token}"))
+                    .body(Body::from(update.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), 1024 * 1024)
+            .await
+            .unwrap();
+        let updated_user: User = serde_json::from_slice(&body).unwrap();
+        assert_eq!(updated_user.name, "Alicia");
+    }
+
+    #[tokio::test]
+    async fn test_update_user_not_found() {
+        // Arrange
+        let app = setup_test_app().await;
+        let token = register_and_login(&app, "grace", "hunter2").await;
+
+        // Act
+        let update = serde_json::json!({ "name": "Nobody" });
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri("/users/999")
+                    .header("Content-Type", "application/json")
+                    .header("Authorization", format!("Bearer {token}"))
+                    .body(Body::from(update.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_delete_user() {
+        // Arrange
+        let app = setup_test_app().await;
+        let token = register_and_login(&app, "heidi", "hunter2").await;
+
+        // Act
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri("/users/1")
+                    .header("Authorization", format!("Bearer {token}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_delete_user_not_found() {
+        // Arrange
+        let app = setup_test_app().await;
+        let token = register_and_login(&app, "ivan", "hunter2").await;
+
+        // Act
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri("/users/999")
+                    .header("Authorization", format!("Bearer {token}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
 }